@@ -284,7 +284,7 @@ pub fn apply_cost_to_market(
     market.amm.net_revenue_since_last_funding = market
         .amm
         .net_revenue_since_last_funding
-        .safe_sub(cost as i64)?;
+        .safe_sub(cost.cast()?)?;
 
     Ok(true)
 }
@@ -468,3 +468,70 @@ mod calculate_auction_params_for_trigger_order {
         assert_eq!(auction_end_price, 99500000);
     }
 }
+
+mod invariants {
+    use crate::controller::position::PositionDirection;
+    use crate::math::auction::{calculate_auction_price, calculate_auction_prices};
+    use crate::math::constants::PRICE_PRECISION_U64;
+    use crate::state::oracle::OraclePriceData;
+    use crate::state::user::{Order, OrderType};
+    use crate::test_utils::{get_invariant_test_iterations, InvariantRng};
+
+    #[test]
+    fn auction_price_between_start_and_end_price() {
+        let mut rng = InvariantRng::new(0xA0C7_10A5);
+
+        for _ in 0..get_invariant_test_iterations() {
+            let oracle_price = rng.range(PRICE_PRECISION_U64 / 10, 100_000 * PRICE_PRECISION_U64);
+            let direction = if rng.bool() {
+                PositionDirection::Long
+            } else {
+                PositionDirection::Short
+            };
+            let limit_price = if rng.bool() {
+                0
+            } else {
+                rng.range(oracle_price * 9 / 10, oracle_price * 11 / 10)
+            };
+
+            let oracle_price_data = OraclePriceData {
+                price: oracle_price as i64,
+                ..OraclePriceData::default()
+            };
+
+            let (auction_start_price, auction_end_price) =
+                calculate_auction_prices(&oracle_price_data, direction, limit_price).unwrap();
+
+            let order = Order {
+                order_type: OrderType::Limit,
+                direction,
+                slot: 0,
+                auction_duration: rng.range(1, 60) as u8,
+                auction_start_price,
+                auction_end_price,
+                ..Order::default()
+            };
+
+            let low = auction_start_price.min(auction_end_price) as u64;
+            let high = auction_start_price.max(auction_end_price) as u64;
+
+            let mut last_price = calculate_auction_price(&order, 0, 1, None).unwrap();
+            assert_eq!(last_price, auction_start_price as u64, "{:?}", order);
+
+            for slot in 0..=(order.auction_duration as u64 + 5) {
+                let price = calculate_auction_price(&order, slot, 1, None).unwrap();
+
+                assert!(low <= price && price <= high, "slot {} {:?}", slot, order);
+
+                // price only moves from start towards end as the auction progresses
+                match direction {
+                    PositionDirection::Long => assert!(price >= last_price, "{:?}", order),
+                    PositionDirection::Short => assert!(price <= last_price, "{:?}", order),
+                }
+                last_price = price;
+            }
+
+            assert_eq!(last_price, auction_end_price as u64, "{:?}", order);
+        }
+    }
+}
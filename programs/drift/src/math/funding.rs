@@ -46,7 +46,7 @@ pub fn calculate_funding_rate_long_short(
         market.amm.net_revenue_since_last_funding = market
             .amm
             .net_revenue_since_last_funding
-            .safe_add(uncapped_funding_pnl as i64)?;
+            .safe_add(uncapped_funding_pnl.cast()?)?;
 
         return Ok((funding_rate, funding_rate, uncapped_funding_pnl));
     }
@@ -74,7 +74,7 @@ pub fn calculate_funding_rate_long_short(
     market.amm.net_revenue_since_last_funding = market
         .amm
         .net_revenue_since_last_funding
-        .safe_sub(capped_funding_pnl.unsigned_abs() as i64)?;
+        .safe_sub(capped_funding_pnl.unsigned_abs().cast()?)?;
 
     let funding_rate_long = if funding_rate < 0 {
         capped_funding_rate
@@ -3113,3 +3113,172 @@ mod calculate_user_equity {
         assert_eq!(net_usd_value, 1000000000);
     }
 }
+
+#[cfg(test)]
+mod invariants {
+    use crate::math::constants::{
+        BASE_PRECISION_I64, MARGIN_PRECISION, PRICE_PRECISION_U64, QUOTE_PRECISION_I64,
+        SPOT_IMF_PRECISION, SPOT_WEIGHT_PRECISION,
+    };
+    use crate::math::margin::{calculate_perp_position_value_and_pnl, MarginRequirementType};
+    use crate::math::position::calculate_base_asset_value_and_pnl_with_oracle_price;
+    use crate::state::oracle::{OraclePriceData, StrictOraclePrice};
+    use crate::state::perp_market::{MarketStatus, PerpMarket};
+    use crate::state::user::PerpPosition;
+    use crate::test_utils::{get_invariant_test_iterations, InvariantRng};
+
+    fn random_perp_market(rng: &mut InvariantRng) -> PerpMarket {
+        let margin_ratio_initial =
+            rng.range(MARGIN_PRECISION as u64 / 50, MARGIN_PRECISION as u64) as u32;
+
+        PerpMarket {
+            status: MarketStatus::Active,
+            margin_ratio_initial,
+            margin_ratio_maintenance: margin_ratio_initial / 2,
+            imf_factor: rng.range(0, SPOT_IMF_PRECISION as u64 / 100) as u32,
+            unrealized_pnl_initial_asset_weight: SPOT_WEIGHT_PRECISION,
+            unrealized_pnl_maintenance_asset_weight: SPOT_WEIGHT_PRECISION,
+            ..PerpMarket::default()
+        }
+    }
+
+    fn perp_position(base_asset_amount: i64, entry_price: u64) -> PerpPosition {
+        let quote_asset_amount =
+            -(base_asset_amount as i128 * entry_price as i128 / BASE_PRECISION_I64 as i128) as i64;
+
+        PerpPosition {
+            base_asset_amount,
+            quote_asset_amount,
+            quote_entry_amount: quote_asset_amount,
+            quote_break_even_amount: quote_asset_amount,
+            ..PerpPosition::default()
+        }
+    }
+
+    fn margin_requirement(
+        position: &PerpPosition,
+        market: &PerpMarket,
+        oracle_price: u64,
+        margin_requirement_type: MarginRequirementType,
+    ) -> u128 {
+        let oracle_price_data = OraclePriceData {
+            price: oracle_price as i64,
+            confidence: 0,
+            delay: 0,
+            has_sufficient_number_of_data_points: true,
+        };
+        let strict_quote_price = StrictOraclePrice::test(QUOTE_PRECISION_I64);
+
+        let (margin_requirement, _, _, _) = calculate_perp_position_value_and_pnl(
+            position,
+            market,
+            &oracle_price_data,
+            &strict_quote_price,
+            margin_requirement_type,
+            0,
+            false,
+        )
+        .unwrap();
+
+        margin_requirement
+    }
+
+    #[test]
+    fn margin_requirement_monotone_in_position_size() {
+        let mut rng = InvariantRng::new(0x3A26_1170);
+
+        for _ in 0..get_invariant_test_iterations() {
+            let market = random_perp_market(&mut rng);
+            let oracle_price = rng.range(PRICE_PRECISION_U64 / 10, 100_000 * PRICE_PRECISION_U64);
+            let entry_price = rng.range(oracle_price / 2, oracle_price * 2);
+            let sign = if rng.bool() { 1 } else { -1 };
+
+            let smaller_base = rng.range(0, 1_000 * BASE_PRECISION_I64 as u64) as i64;
+            let larger_base = smaller_base + rng.range(1, 1_000 * BASE_PRECISION_I64 as u64) as i64;
+
+            for margin_requirement_type in [
+                MarginRequirementType::Initial,
+                MarginRequirementType::Fill,
+                MarginRequirementType::Maintenance,
+            ] {
+                let smaller = margin_requirement(
+                    &perp_position(sign * smaller_base, entry_price),
+                    &market,
+                    oracle_price,
+                    margin_requirement_type,
+                );
+                let larger = margin_requirement(
+                    &perp_position(sign * larger_base, entry_price),
+                    &market,
+                    oracle_price,
+                    margin_requirement_type,
+                );
+
+                assert!(
+                    smaller <= larger,
+                    "{:?} base {} vs {} oracle {} {:?}",
+                    margin_requirement_type,
+                    sign * smaller_base,
+                    sign * larger_base,
+                    oracle_price,
+                    market
+                );
+            }
+
+            // initial is always at least as strict as maintenance
+            let position = perp_position(sign * larger_base, entry_price);
+            assert!(
+                margin_requirement(
+                    &position,
+                    &market,
+                    oracle_price,
+                    MarginRequirementType::Initial
+                ) >= margin_requirement(
+                    &position,
+                    &market,
+                    oracle_price,
+                    MarginRequirementType::Maintenance
+                ),
+                "{:?} {:?}",
+                position,
+                market
+            );
+        }
+    }
+
+    #[test]
+    fn pnl_of_position_and_inverse_sum_to_zero() {
+        let mut rng = InvariantRng::new(0x9A1E_0FF5);
+
+        for _ in 0..get_invariant_test_iterations() {
+            let oracle_price = rng.range(1, 100_000 * PRICE_PRECISION_U64) as i64;
+            let base_asset_amount = rng.range(1, 1_000_000 * BASE_PRECISION_I64 as u64) as i64;
+            let quote_asset_amount =
+                -(rng.range(0, 1_000_000_000 * QUOTE_PRECISION_I64 as u64) as i64);
+
+            let position = PerpPosition {
+                base_asset_amount,
+                quote_asset_amount,
+                ..PerpPosition::default()
+            };
+            let inverse_position = PerpPosition {
+                base_asset_amount: -base_asset_amount,
+                quote_asset_amount: -quote_asset_amount,
+                ..PerpPosition::default()
+            };
+
+            let (base_asset_value, pnl) =
+                calculate_base_asset_value_and_pnl_with_oracle_price(&position, oracle_price)
+                    .unwrap();
+            let (inverse_base_asset_value, inverse_pnl) =
+                calculate_base_asset_value_and_pnl_with_oracle_price(
+                    &inverse_position,
+                    oracle_price,
+                )
+                .unwrap();
+
+            assert_eq!(base_asset_value, inverse_base_asset_value, "{:?}", position);
+            assert_eq!(pnl + inverse_pnl, 0, "{:?}", position);
+        }
+    }
+}
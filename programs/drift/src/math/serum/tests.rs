@@ -1,4 +1,5 @@
 use crate::controller::position::PositionDirection;
+use crate::error::ErrorCode;
 use crate::math::constants::{LAMPORTS_PER_SOL_U64, PRICE_PRECISION_U64};
 use crate::math::serum::{
     calculate_price_from_serum_limit_price, calculate_serum_limit_price,
@@ -83,3 +84,24 @@ fn test_calculate_price_from_serum_limit_price() {
 
     assert_eq!(price, 100 * PRICE_PRECISION_U64);
 }
+
+#[test]
+fn test_calculate_serum_limit_price_overflow() {
+    // limit price in lots no longer fits in a u64, must fail instead of truncating
+    let limit_price = u64::MAX;
+    let pc_lot_size = 1_u64;
+    let coin_lot_size = 2;
+    let coin_decimals = 0;
+
+    for direction in [PositionDirection::Long, PositionDirection::Short] {
+        let serum_limit_price = calculate_serum_limit_price(
+            limit_price,
+            pc_lot_size,
+            coin_decimals,
+            coin_lot_size,
+            direction,
+        );
+
+        assert_eq!(serum_limit_price, Err(ErrorCode::CastingFailure));
+    }
+}
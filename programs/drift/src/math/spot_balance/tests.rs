@@ -0,0 +1,74 @@
+mod invariants {
+    use crate::math::constants::SPOT_CUMULATIVE_INTEREST_PRECISION;
+    use crate::math::safe_math::SafeMath;
+    use crate::math::spot_balance::{get_spot_balance, get_token_amount};
+    use crate::state::spot_market::{SpotBalanceType, SpotMarket};
+    use crate::test_utils::{get_invariant_test_iterations, InvariantRng};
+
+    fn random_spot_market(rng: &mut InvariantRng) -> SpotMarket {
+        let decimals = [6_u32, 8, 9][rng.range(0, 2) as usize];
+        let cumulative_deposit_interest = rng.range(
+            SPOT_CUMULATIVE_INTEREST_PRECISION as u64,
+            3 * SPOT_CUMULATIVE_INTEREST_PRECISION as u64,
+        ) as u128;
+        let cumulative_borrow_interest = rng.range(
+            cumulative_deposit_interest as u64,
+            4 * SPOT_CUMULATIVE_INTEREST_PRECISION as u64,
+        ) as u128;
+
+        SpotMarket {
+            decimals,
+            cumulative_deposit_interest,
+            cumulative_borrow_interest,
+            ..SpotMarket::default()
+        }
+    }
+
+    #[test]
+    fn token_amount_round_trips_through_scaled_balance() {
+        let mut rng = InvariantRng::new(0x5B07_BA1A);
+
+        for _ in 0..get_invariant_test_iterations() {
+            let spot_market = random_spot_market(&mut rng);
+            let token_amount = rng.range(1_000, 1_000_000_000_000_000) as u128;
+
+            let precision = 10_u128.pow(19 - spot_market.decimals);
+
+            // deposits round down, so tokens can only be lost, by at most one unit of scaled balance
+            let deposit_unit = spot_market
+                .cumulative_deposit_interest
+                .safe_div_ceil(precision)
+                .unwrap();
+            let balance =
+                get_spot_balance(token_amount, &spot_market, &SpotBalanceType::Deposit, false)
+                    .unwrap();
+            let round_trip =
+                get_token_amount(balance, &spot_market, &SpotBalanceType::Deposit).unwrap();
+            assert!(round_trip <= token_amount, "{:?}", spot_market);
+            assert!(
+                token_amount - round_trip <= deposit_unit,
+                "token_amount {} {:?}",
+                token_amount,
+                spot_market
+            );
+
+            // borrows round up, so the liability can only grow, by at most one unit of scaled balance
+            let borrow_unit = spot_market
+                .cumulative_borrow_interest
+                .safe_div_ceil(precision)
+                .unwrap();
+            let balance =
+                get_spot_balance(token_amount, &spot_market, &SpotBalanceType::Borrow, true)
+                    .unwrap();
+            let round_trip =
+                get_token_amount(balance, &spot_market, &SpotBalanceType::Borrow).unwrap();
+            assert!(round_trip >= token_amount, "{:?}", spot_market);
+            assert!(
+                round_trip - token_amount <= borrow_unit,
+                "token_amount {} {:?}",
+                token_amount,
+                spot_market
+            );
+        }
+    }
+}
@@ -66,14 +66,14 @@ impl OrderParams {
                         // if auction duration is empty and limit doesnt cross vamm premium, return early
                         return Ok(());
                     } else {
-                        let new_auction_start_price = new_auction_start_price.min(est_ask as i64);
+                        let new_auction_start_price = new_auction_start_price.min(est_ask.cast()?);
                         msg!(
                             "Updating auction start price to {}",
                             new_auction_start_price
                         );
                         self.auction_start_price = Some(new_auction_start_price);
                         msg!("Updating auction end price to {}", self.price);
-                        self.auction_end_price = Some(self.price as i64);
+                        self.auction_end_price = Some(self.price.cast()?);
                     }
                 }
                 PositionDirection::Short => {
@@ -83,14 +83,14 @@ impl OrderParams {
                         // if auction duration is empty and limit doesnt cross vamm discount, return early
                         return Ok(());
                     } else {
-                        let new_auction_start_price = new_auction_start_price.max(est_bid as i64);
+                        let new_auction_start_price = new_auction_start_price.max(est_bid.cast()?);
                         msg!(
                             "Updating auction start price to {}",
                             new_auction_start_price
                         );
                         self.auction_start_price = Some(new_auction_start_price);
                         msg!("Updating auction end price to {}", self.price);
-                        self.auction_end_price = Some(self.price as i64);
+                        self.auction_end_price = Some(self.price.cast()?);
                     }
                 }
             }
@@ -119,7 +119,7 @@ impl OrderParams {
 
             if self.auction_end_price.is_none() {
                 msg!("Updating auction end price to {}", new_auction_start_price);
-                self.auction_end_price = Some(self.price as i64);
+                self.auction_end_price = Some(self.price.cast()?);
             }
         }
 
@@ -279,7 +279,7 @@ impl OrderParams {
                 OrderParams::get_perp_baseline_start_price_offset(perp_market, direction)?;
             let mut auction_start_price = oracle_price.safe_add(auction_start_price_offset)?;
 
-            let limit_price = limit_price as i64;
+            let limit_price = limit_price.cast::<i64>()?;
             if direction == PositionDirection::Long {
                 auction_start_price = auction_start_price.min(limit_price)
             } else {
@@ -1056,3 +1056,27 @@ mod get_close_perp_params {
         validate_order(&order, &perp_market, Some(oracle_price), slot).unwrap();
     }
 }
+
+mod derive_market_order_auction_params {
+    use crate::error::ErrorCode;
+    use crate::state::perp_market::PerpMarket;
+    use crate::{OrderParams, PositionDirection, PRICE_PRECISION_I64};
+
+    #[test]
+    fn limit_price_above_i64_max() {
+        let perp_market = PerpMarket::default();
+        let oracle_price = 100 * PRICE_PRECISION_I64;
+
+        // would previously wrap to a negative auction end price
+        let limit_price = i64::MAX as u64 + 1;
+
+        let result = OrderParams::derive_market_order_auction_params(
+            &perp_market,
+            PositionDirection::Long,
+            oracle_price,
+            limit_price,
+        );
+
+        assert_eq!(result, Err(ErrorCode::CastingFailure));
+    }
+}
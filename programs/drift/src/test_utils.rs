@@ -43,6 +43,45 @@ pub fn get_spot_positions(spot_position: SpotPosition) -> [SpotPosition; 8] {
     spot_positions
 }
 
+/// Deterministic xorshift generator used by the math invariant tests so failures are reproducible
+/// from the printed seed without pulling a property testing crate into the program
+pub struct InvariantRng {
+    state: u64,
+}
+
+impl InvariantRng {
+    pub fn new(seed: u64) -> Self {
+        InvariantRng { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// uniform in [low, high]
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// Number of cases each invariant test runs. Kept small for the normal test run, set
+/// DRIFT_INVARIANT_ITERATIONS to run the extended mode
+pub fn get_invariant_test_iterations() -> u64 {
+    std::env::var("DRIFT_INVARIANT_ITERATIONS")
+        .ok()
+        .and_then(|iterations| iterations.parse().ok())
+        .unwrap_or(256)
+}
+
 pub fn get_account_bytes<T: bytemuck::Pod>(account: &mut T) -> BytesMut {
     let mut bytes = BytesMut::new();
     let data = bytemuck::bytes_of_mut(account);